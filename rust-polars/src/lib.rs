@@ -1,9 +1,11 @@
-use jni::objects::{JClass, JString, JByteArray};
-use jni::sys::{jstring, jint, jlong};
+use jni::objects::{JClass, JString, JByteArray, JObjectArray};
+use jni::sys::{jstring, jint, jlong, jbyteArray};
 use jni::JNIEnv;
 use polars::prelude::*;
+use serde::Deserialize;
 use serde_json::json;
 use std::collections::HashMap;
+use std::fs::File;
 use std::io::Cursor;
 use std::sync::Mutex;
 use std::collections::BTreeMap;
@@ -75,6 +77,162 @@ pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_groupByAndSum(
     }
 }
 
+// === Columnar IO: Parquet and Arrow IPC ===
+
+/// Read a Parquet file into a DataFrame, optionally projecting to `columns`.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_readParquet(
+    mut env: JNIEnv,
+    _class: JClass,
+    path: JString,
+    columns: JObjectArray,
+) -> jstring {
+    let path_str: String = env.get_string(&path).unwrap().into();
+    let projection = jstring_array_to_vec(&mut env, &columns);
+    let result = read_parquet_file(&path_str, &projection);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn read_parquet_file(path: &str, columns: &[String]) -> PolarsResult<String> {
+    let file = File::open(path)
+        .map_err(|e| PolarsError::ComputeError(format!("Failed to open parquet file: {}", e).into()))?;
+    let mut reader = ParquetReader::new(file);
+    if !columns.is_empty() {
+        reader = reader.with_columns(Some(columns.to_vec()));
+    }
+    let df = reader.finish()?;
+    dataframe_to_json(&df)
+}
+
+/// Write the CSV at `csv_path` out as a Parquet file using `compression`
+/// ("snappy", "zstd", "lz4", or "uncompressed"/"none", defaulting to snappy
+/// for anything else).
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_writeParquet(
+    mut env: JNIEnv,
+    _class: JClass,
+    csv_path: JString,
+    out_path: JString,
+    compression: JString,
+) -> jstring {
+    let csv_path_str: String = env.get_string(&csv_path).unwrap().into();
+    let out_path_str: String = env.get_string(&out_path).unwrap().into();
+    let compression_str: String = env.get_string(&compression).unwrap().into();
+    let result = write_parquet_file(&csv_path_str, &out_path_str, &compression_str);
+    match result {
+        Ok(()) => env.new_string(format!("Written to {}", out_path_str)).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn write_parquet_file(csv_path: &str, out_path: &str, compression: &str) -> PolarsResult<()> {
+    let mut df = CsvReader::from_path(csv_path)?.finish()?;
+    let file = File::create(out_path)
+        .map_err(|e| PolarsError::ComputeError(format!("Failed to create parquet file: {}", e).into()))?;
+    ParquetWriter::new(file)
+        .with_compression(parse_parquet_compression(compression))
+        .finish(&mut df)?;
+    Ok(())
+}
+
+fn parse_parquet_compression(name: &str) -> ParquetCompression {
+    match name.to_lowercase().as_str() {
+        "zstd" => ParquetCompression::Zstd(None),
+        "lz4" => ParquetCompression::Lz4Raw,
+        "uncompressed" | "none" => ParquetCompression::Uncompressed,
+        _ => ParquetCompression::Snappy,
+    }
+}
+
+/// Read an Arrow IPC (Feather V2) file into a DataFrame, optionally
+/// projecting to `columns`.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_readIpc(
+    mut env: JNIEnv,
+    _class: JClass,
+    path: JString,
+    columns: JObjectArray,
+) -> jstring {
+    let path_str: String = env.get_string(&path).unwrap().into();
+    let projection = jstring_array_to_vec(&mut env, &columns);
+    let result = read_ipc_file(&path_str, &projection);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn read_ipc_file(path: &str, columns: &[String]) -> PolarsResult<String> {
+    let file = File::open(path)
+        .map_err(|e| PolarsError::ComputeError(format!("Failed to open ipc file: {}", e).into()))?;
+    let mut reader = IpcReader::new(file);
+    if !columns.is_empty() {
+        reader = reader.with_columns(Some(columns.to_vec()));
+    }
+    let df = reader.finish()?;
+    dataframe_to_json(&df)
+}
+
+/// Write the CSV at `csv_path` out as an Arrow IPC file using `compression`
+/// ("zstd" or "lz4", defaulting to uncompressed for anything else).
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_writeIpc(
+    mut env: JNIEnv,
+    _class: JClass,
+    csv_path: JString,
+    out_path: JString,
+    compression: JString,
+) -> jstring {
+    let csv_path_str: String = env.get_string(&csv_path).unwrap().into();
+    let out_path_str: String = env.get_string(&out_path).unwrap().into();
+    let compression_str: String = env.get_string(&compression).unwrap().into();
+    let result = write_ipc_file(&csv_path_str, &out_path_str, &compression_str);
+    match result {
+        Ok(()) => env.new_string(format!("Written to {}", out_path_str)).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn write_ipc_file(csv_path: &str, out_path: &str, compression: &str) -> PolarsResult<()> {
+    let mut df = CsvReader::from_path(csv_path)?.finish()?;
+    let file = File::create(out_path)
+        .map_err(|e| PolarsError::ComputeError(format!("Failed to create ipc file: {}", e).into()))?;
+    IpcWriter::new(file)
+        .with_compression(parse_ipc_compression(compression))
+        .finish(&mut df)?;
+    Ok(())
+}
+
+fn parse_ipc_compression(name: &str) -> Option<IpcCompression> {
+    match name.to_lowercase().as_str() {
+        "zstd" => Some(IpcCompression::ZSTD),
+        "lz4" => Some(IpcCompression::LZ4),
+        _ => None,
+    }
+}
+
+/// Convert a Java `String[]` argument into a `Vec<String>`, treating a null
+/// or empty array as "no projection" (read all columns).
+fn jstring_array_to_vec(env: &mut JNIEnv, array: &JObjectArray) -> Vec<String> {
+    if array.is_null() {
+        return Vec::new();
+    }
+    let len = env.get_array_length(array).unwrap_or(0);
+    let mut result = Vec::with_capacity(len as usize);
+    for i in 0..len {
+        if let Ok(obj) = env.get_object_array_element(array, i) {
+            let jstr = JString::from(obj);
+            if let Ok(s) = env.get_string(&jstr) {
+                result.push(s.into());
+            }
+        }
+    }
+    result
+}
+
 /// Create a sample DataFrame for testing
 fn create_sample_dataframe() -> PolarsResult<String> {
     let df = df! [
@@ -120,78 +278,269 @@ fn group_by_and_sum(csv_path: &str, group_col: &str, sum_col: &str) -> PolarsRes
     dataframe_to_json(&grouped)
 }
 
+/// Convert a single cell to a `serde_json::Value`, recursing into
+/// `DataType::Struct` columns (e.g. from ndjson schema inference) so nested
+/// fields come back as JSON objects instead of being stringified by the
+/// fallback arm.
+fn series_value_to_json(series: &Series, row: usize) -> serde_json::Value {
+    match series.dtype() {
+        DataType::Utf8 => {
+            if let Ok(val) = series.utf8() {
+                json!(val.get(row).unwrap_or(""))
+            } else {
+                json!("")
+            }
+        }
+        DataType::Int64 => {
+            if let Ok(val) = series.i64() {
+                json!(val.get(row).unwrap_or(0))
+            } else {
+                json!(0)
+            }
+        }
+        DataType::Float64 => {
+            if let Ok(val) = series.f64() {
+                json!(val.get(row).unwrap_or(0.0))
+            } else {
+                json!(0.0)
+            }
+        }
+        DataType::Int32 => {
+            if let Ok(val) = series.i32() {
+                json!(val.get(row).unwrap_or(0))
+            } else {
+                json!(0)
+            }
+        }
+        DataType::Float32 => {
+            if let Ok(val) = series.f32() {
+                json!(val.get(row).unwrap_or(0.0))
+            } else {
+                json!(0.0)
+            }
+        }
+        DataType::Struct(_) => {
+            if let Ok(struct_chunked) = series.struct_() {
+                let mut obj = serde_json::Map::new();
+                for field in struct_chunked.fields() {
+                    obj.insert(field.name().to_string(), series_value_to_json(field, row));
+                }
+                serde_json::Value::Object(obj)
+            } else {
+                json!(null)
+            }
+        }
+        _ => {
+            // Fallback: convert to string
+            if let Ok(any_val) = series.get(row) {
+                json!(any_val.to_string())
+            } else {
+                json!("null")
+            }
+        }
+    }
+}
+
 /// Convert DataFrame to JSON string
 fn dataframe_to_json(df: &DataFrame) -> PolarsResult<String> {
     let mut result = Vec::new();
-    
+
     for row in 0..df.height() {
         let mut row_map = HashMap::new();
-        
+
         for (col_idx, series) in df.get_columns().iter().enumerate() {
             let col_name = df.get_column_names()[col_idx];
-            let value = match series.dtype() {
-                DataType::Utf8 => {
-                    if let Ok(val) = series.utf8() {
-                        json!(val.get(row).unwrap_or(""))
-                    } else {
-                        json!("")
-                    }
-                }
-                DataType::Int64 => {
-                    if let Ok(val) = series.i64() {
-                        json!(val.get(row).unwrap_or(0))
-                    } else {
-                        json!(0)
-                    }
-                }
-                DataType::Float64 => {
-                    if let Ok(val) = series.f64() {
-                        json!(val.get(row).unwrap_or(0.0))
-                    } else {
-                        json!(0.0)
-                    }
-                }
-                DataType::Int32 => {
-                    if let Ok(val) = series.i32() {
-                        json!(val.get(row).unwrap_or(0))
-                    } else {
-                        json!(0)
-                    }
-                }
-                DataType::Float32 => {
-                    if let Ok(val) = series.f32() {
-                        json!(val.get(row).unwrap_or(0.0))
-                    } else {
-                        json!(0.0)
-                    }
-                }
-                _ => {
-                    // Fallback: convert to string
-                    if let Ok(any_val) = series.get(row) {
-                        json!(any_val.to_string())
-                    } else {
-                        json!("null")
-                    }
-                }
-            };
-            row_map.insert(col_name, value);
+            row_map.insert(col_name, series_value_to_json(series, row));
         }
         result.push(row_map);
     }
-    
+
     Ok(serde_json::to_string(&result).unwrap())
 }
 
+// === Arrow IPC output ===
+
+/// Serialize a DataFrame to an Arrow IPC stream buffer.
+fn dataframe_to_arrow_ipc(df: &DataFrame) -> PolarsResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    IpcStreamWriter::new(&mut buf).finish(&mut df.clone())?;
+    Ok(buf)
+}
+
+/// Convert a `PolarsResult<Vec<u8>>` into a `JByteArray`. On error a
+/// `java.lang.RuntimeException` carrying the error message is thrown and
+/// null is returned (the JSON-returning functions instead return an
+/// "Error: ..." string).
+fn arrow_ipc_result_to_jbytearray(env: &mut JNIEnv, result: PolarsResult<Vec<u8>>) -> jbyteArray {
+    match result {
+        Ok(bytes) => env.byte_array_from_slice(&bytes).unwrap().into_raw(),
+        Err(e) => {
+            let _ = env.throw_new("java/lang/RuntimeException", format!("{}", e));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Read a CSV file and return the DataFrame as an Arrow IPC stream buffer.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_readCsvArrowIpc(
+    mut env: JNIEnv,
+    _class: JClass,
+    path: JString,
+) -> jbyteArray {
+    let path_str: String = env.get_string(&path).unwrap().into();
+    let result = CsvReader::from_path(&path_str)
+        .and_then(|r| r.finish())
+        .and_then(|df| dataframe_to_arrow_ipc(&df));
+    arrow_ipc_result_to_jbytearray(&mut env, result)
+}
+
+/// Filter a CSV-backed DataFrame and return the result as an Arrow IPC
+/// stream buffer.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_filterDataFrameArrowIpc(
+    mut env: JNIEnv,
+    _class: JClass,
+    csv_path: JString,
+    column: JString,
+    min_value: f64,
+) -> jbyteArray {
+    let csv_path_str: String = env.get_string(&csv_path).unwrap().into();
+    let column_str: String = env.get_string(&column).unwrap().into();
+    let result = CsvReader::from_path(&csv_path_str)
+        .and_then(|r| r.finish())
+        .and_then(|df| df.lazy().filter(col(&column_str).gt_eq(lit(min_value))).collect())
+        .and_then(|df| dataframe_to_arrow_ipc(&df));
+    arrow_ipc_result_to_jbytearray(&mut env, result)
+}
+
+/// Group-by-and-sum a CSV-backed DataFrame and return the result as an
+/// Arrow IPC stream buffer.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_groupByAndSumArrowIpc(
+    mut env: JNIEnv,
+    _class: JClass,
+    csv_path: JString,
+    group_col: JString,
+    sum_col: JString,
+) -> jbyteArray {
+    let csv_path_str: String = env.get_string(&csv_path).unwrap().into();
+    let group_col_str: String = env.get_string(&group_col).unwrap().into();
+    let sum_col_str: String = env.get_string(&sum_col).unwrap().into();
+    let result = CsvReader::from_path(&csv_path_str)
+        .and_then(|r| r.finish())
+        .and_then(|df| df.lazy().group_by([col(&group_col_str)]).agg([col(&sum_col_str).sum()]).collect())
+        .and_then(|df| dataframe_to_arrow_ipc(&df));
+    arrow_ipc_result_to_jbytearray(&mut env, result)
+}
+
+/// Get final results from a stream processor as an Arrow IPC stream buffer.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_getStreamResultsArrowIpc(
+    mut env: JNIEnv,
+    _class: JClass,
+    processor_id: jlong,
+    operation: JString,
+) -> jbyteArray {
+    let operation_str: String = env.get_string(&operation).unwrap().into();
+    let result = compute_stream_result_df(processor_id, &operation_str)
+        .and_then(|maybe_df| match maybe_df {
+            Some(df) => dataframe_to_arrow_ipc(&df),
+            None => Ok(Vec::new()),
+        });
+    arrow_ipc_result_to_jbytearray(&mut env, result)
+}
+
 // === Streaming Processing Functions ===
 
 /// Stream processor session state
 static STREAM_PROCESSORS: Mutex<BTreeMap<jlong, StreamProcessor>> = Mutex::new(BTreeMap::new());
 static NEXT_PROCESSOR_ID: Mutex<jlong> = Mutex::new(1);
 
+/// Running state for a stream processor. CSV chunks accumulate as typed
+/// DataFrames rather than `Vec<HashMap<String, Value>>` rows, so no
+/// per-cell JSON round-trip happens until a result is requested. A
+/// `groupby:` operation additionally maintains a running per-group sum that
+/// is merged incrementally as chunks arrive, so `getStreamResults` answers
+/// that groupby without materializing every accumulated row. A different
+/// operation requested at fetch time still concatenates the chunks.
+///
+/// Ndjson sessions (`initNdjsonStreamProcessor`/`processNdjsonChunk`/
+/// `getNdjsonStreamResults`) reuse this same struct and processor table,
+/// buffering into `ndjson_rows` instead of `accumulated_chunks` since the
+/// ndjson schema pass needs the raw JSON rows rather than a DataFrame.
 struct StreamProcessor {
     operation: String,
-    accumulated_data: Vec<HashMap<String, serde_json::Value>>,
-    schema: Option<Vec<String>>,
+    accumulated_chunks: Vec<DataFrame>,
+    running_group_sums: Option<HashMap<String, f64>>,
+    ndjson_rows: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl StreamProcessor {
+    fn groupby_columns(&self) -> Option<(&str, &str)> {
+        parse_groupby_operation(&self.operation)
+    }
+
+    /// Fold a newly-arrived chunk's group sums into the running totals.
+    fn merge_chunk_group_sums(&mut self, chunk: &DataFrame) -> PolarsResult<()> {
+        let Some((group_col, sum_col)) = self.groupby_columns() else {
+            return Ok(());
+        };
+        let running = self.running_group_sums.get_or_insert_with(HashMap::new);
+        fold_batch_group_sums(chunk, group_col, sum_col, running)
+    }
+}
+
+/// Parse a `groupby:<group_col>:<sum_col>` operation string, returning
+/// `None` for any other operation or a malformed one (missing a part).
+fn parse_groupby_operation(operation: &str) -> Option<(&str, &str)> {
+    if !operation.starts_with("groupby:") {
+        return None;
+    }
+    let parts: Vec<&str> = operation.split(':').collect();
+    if parts.len() >= 3 {
+        Some((parts[1], parts[2]))
+    } else {
+        None
+    }
+}
+
+/// Parse a `filter:<column>:<min_value>` operation string, returning `None`
+/// for any other operation or a malformed one (missing a part).
+fn parse_filter_operation(operation: &str) -> Option<(&str, f64)> {
+    if !operation.starts_with("filter:") {
+        return None;
+    }
+    let parts: Vec<&str> = operation.split(':').collect();
+    if parts.len() >= 3 {
+        Some((parts[1], parts[2].parse().unwrap_or(0.0)))
+    } else {
+        None
+    }
+}
+
+/// Group `batch` by `group_col` and sum `sum_col`, folding the result into
+/// `running` — shared by the per-chunk (`StreamProcessor`) and per-batch
+/// (`stream_process_csv`) incremental groupby paths.
+fn fold_batch_group_sums(batch: &DataFrame, group_col: &str, sum_col: &str, running: &mut HashMap<String, f64>) -> PolarsResult<()> {
+    let batch_sums = batch
+        .clone()
+        .lazy()
+        .group_by([col(group_col)])
+        .agg([col(sum_col).sum()])
+        .collect()?;
+
+    let keys = batch_sums.column(group_col)?.cast(&DataType::Utf8)?;
+    let keys = keys.utf8()?;
+    let sums = batch_sums.column(sum_col)?.cast(&DataType::Float64)?;
+    let sums = sums.f64()?;
+
+    for (key, sum) in keys.into_iter().zip(sums.into_iter()) {
+        if let (Some(key), Some(sum)) = (key, sum) {
+            *running.entry(key.to_string()).or_insert(0.0) += sum;
+        }
+    }
+    Ok(())
 }
 
 /// Initialize a streaming processor session
@@ -202,18 +551,24 @@ pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_initStreamProcessor(
     operation: JString,
 ) -> jlong {
     let operation_str: String = env.get_string(&operation).unwrap().into();
-    
+    register_stream_processor(operation_str)
+}
+
+/// Register a new stream processor under a fresh ID, shared by the CSV and
+/// ndjson `init*StreamProcessor` entry points.
+fn register_stream_processor(operation: String) -> jlong {
     let processor = StreamProcessor {
-        operation: operation_str,
-        accumulated_data: Vec::new(),
-        schema: None,
+        operation,
+        accumulated_chunks: Vec::new(),
+        running_group_sums: None,
+        ndjson_rows: Vec::new(),
     };
-    
+
     let mut processors = STREAM_PROCESSORS.lock().unwrap();
     let mut next_id = NEXT_PROCESSOR_ID.lock().unwrap();
     let processor_id = *next_id;
     *next_id += 1;
-    
+
     processors.insert(processor_id, processor);
     processor_id
 }
@@ -235,34 +590,36 @@ pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_processCSVChunk(
 
 fn process_csv_chunk_internal(env: &mut JNIEnv, processor_id: jlong, chunk_data: JByteArray) -> PolarsResult<String> {
     // Get chunk data as bytes
-    let chunk_bytes = env.convert_byte_array(chunk_data).map_err(|e| 
+    let chunk_bytes = env.convert_byte_array(chunk_data).map_err(|e|
         PolarsError::ComputeError(format!("Failed to read chunk data: {}", e).into()))?;
-    
+
     let chunk_str = String::from_utf8(chunk_bytes).map_err(|e|
         PolarsError::ComputeError(format!("Invalid UTF-8 in chunk: {}", e).into()))?;
-    
-    // Parse CSV chunk
+
+    // Parse CSV chunk. Date parsing is enabled so a `rolling_by:` time column
+    // (see `apply_rolling_operation`) lands as Date/Datetime rather than
+    // Utf8, which `group_by_dynamic` requires.
     let cursor = Cursor::new(chunk_str.as_bytes());
     let df = CsvReader::new(cursor)
+        .with_try_parse_dates(true)
         .finish()?;
-    
+
     // Process according to operation
     let mut processors = STREAM_PROCESSORS.lock().unwrap();
     let processor = processors.get_mut(&processor_id).ok_or_else(||
         PolarsError::ComputeError("Invalid processor ID".into()))?;
-    
-    // Store schema from first chunk
-    if processor.schema.is_none() {
-        processor.schema = Some(df.get_column_names().iter().map(|s| s.to_string()).collect());
-    }
-    
-    // Convert to JSON and accumulate
-    let chunk_json = dataframe_to_json_vec(&df)?;
-    processor.accumulated_data.extend(chunk_json);
-    
+
+    // Fold this chunk's group sums into the running aggregation immediately,
+    // so the init-time groupby result never needs the full accumulated history.
+    processor.merge_chunk_group_sums(&df)?;
+
+    let processed_rows = df.height();
+    processor.accumulated_chunks.push(df);
+    let total_accumulated: usize = processor.accumulated_chunks.iter().map(|c| c.height()).sum();
+
     // Return progress info
-    Ok(format!("{{\"processed_rows\": {}, \"total_accumulated\": {}}}", 
-               df.height(), processor.accumulated_data.len()))
+    Ok(format!("{{\"processed_rows\": {}, \"total_accumulated\": {}}}",
+               processed_rows, total_accumulated))
 }
 
 /// Get final results from stream processor
@@ -282,19 +639,56 @@ pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_getStreamResults(
 }
 
 fn get_stream_results_internal(processor_id: jlong, operation: &str) -> PolarsResult<String> {
-    let mut processors = STREAM_PROCESSORS.lock().unwrap();
+    match compute_stream_result_df(processor_id, operation)? {
+        Some(result_df) => dataframe_to_json(&result_df),
+        None => Ok("[]".to_string()),
+    }
+}
+
+/// Build the accumulated-and-operated-on DataFrame for a stream processor,
+/// shared by the JSON (`getStreamResults`) and Arrow IPC
+/// (`getStreamResultsArrowIpc`) output paths. Returns `None` when there's no
+/// accumulated data yet. A `groupby:` equal to the init-time operation reads
+/// straight from the running per-group sums maintained by `processCSVChunk`
+/// rather than concatenating every chunk; every other operation (`filter:`,
+/// `rolling:`/`rolling_by:`, a different `groupby:`, and the pass-through
+/// case) works on the concatenated frame.
+fn compute_stream_result_df(processor_id: jlong, operation: &str) -> PolarsResult<Option<DataFrame>> {
+    let processors = STREAM_PROCESSORS.lock().unwrap();
     let processor = processors.get(&processor_id).ok_or_else(||
         PolarsError::ComputeError("Invalid processor ID".into()))?;
-    
-    if processor.accumulated_data.is_empty() {
-        return Ok("[]".to_string());
+
+    if processor.accumulated_chunks.is_empty() {
+        return Ok(None);
     }
-    
-    // Create DataFrame from accumulated data
-    let df = json_vec_to_dataframe(&processor.accumulated_data, &processor.schema)?;
-    
-    // Apply operation
-    let result_df = match operation {
+
+    // The caller picks the operation at fetch time (matching the ndjson
+    // sibling `get_ndjson_stream_results_internal`), falling back to the
+    // init-time operation only when none is given.
+    let op = if operation.is_empty() { processor.operation.as_str() } else { operation };
+
+    // The running per-group sums are only folded for the processor's
+    // init-time operation (see `merge_chunk_group_sums`), so they're a valid
+    // shortcut only when the caller asks for that same groupby; any other
+    // requested operation falls through to a fresh computation below.
+    if op == processor.operation {
+        if let (Some((group_col, sum_col)), Some(running)) =
+            (parse_groupby_operation(op), processor.running_group_sums.as_ref())
+        {
+            let (groups, sums): (Vec<&str>, Vec<f64>) = running.iter().map(|(k, v)| (k.as_str(), *v)).unzip();
+            return Ok(Some(DataFrame::new(vec![
+                Series::new(group_col, groups),
+                Series::new(sum_col, sums),
+            ])?));
+        }
+    }
+
+    let mut df = processor.accumulated_chunks[0].clone();
+    for chunk in &processor.accumulated_chunks[1..] {
+        df.vstack_mut(chunk)?;
+    }
+
+    let result_df = match op {
         op if op.starts_with("filter:") => {
             let parts: Vec<&str> = op.split(':').collect();
             if parts.len() >= 3 {
@@ -307,23 +701,21 @@ fn get_stream_results_internal(processor_id: jlong, operation: &str) -> PolarsRe
                 df
             }
         }
-        op if op.starts_with("groupby:") => {
-            let parts: Vec<&str> = op.split(':').collect();
-            if parts.len() >= 3 {
-                let group_col = parts[1];
-                let sum_col = parts[2];
-                df.lazy()
-                    .group_by([col(group_col)])
-                    .agg([col(sum_col).sum()])
-                    .collect()?
-            } else {
-                df
-            }
+        op if op.starts_with("groupby:") => match parse_groupby_operation(op) {
+            Some((group_col, sum_col)) => df
+                .lazy()
+                .group_by([col(group_col)])
+                .agg([col(sum_col).sum()])
+                .collect()?,
+            None => df,
+        },
+        op if op.starts_with("rolling:") || op.starts_with("rolling_by:") => {
+            apply_rolling_operation(df, op)?
         }
         _ => df
     };
-    
-    dataframe_to_json(&result_df)
+
+    Ok(Some(result_df))
 }
 
 /// Close and cleanup stream processor
@@ -348,7 +740,7 @@ pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_streamProcessCSV(
 ) -> jstring {
     let file_path_str: String = env.get_string(&file_path).unwrap().into();
     let operation_str: String = env.get_string(&operation).unwrap().into();
-    
+
     let result = stream_process_csv(&file_path_str, chunk_size as usize, &operation_str);
     match result {
         Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
@@ -356,147 +748,805 @@ pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_streamProcessCSV(
     }
 }
 
-fn stream_process_csv(file_path: &str, _chunk_size: usize, operation: &str) -> PolarsResult<String> {
-    // Read CSV file using regular reader for now
-    let df = CsvReader::from_path(file_path)?.finish()?;
-    let lf = df.lazy();
-    
-    // Apply operation as lazy computation
-    let processed_lf = match operation {
-        op if op.starts_with("filter:") => {
-            let parts: Vec<&str> = op.split(':').collect();
-            if parts.len() >= 3 {
-                let column = parts[1];
-                let min_value: f64 = parts[2].parse().unwrap_or(0.0);
-                lf.filter(col(column).gt_eq(lit(min_value)))
+/// Process a large CSV file in bounded memory using Polars' batched reader:
+/// each batch of `chunk_size` rows is read and, for `filter:` and `groupby:`,
+/// the operation is applied to it so only the running result is kept.
+/// `filter:` batches accumulate their (already small) matches; `groupby:`
+/// maintains a running per-group sum across batches, mirroring
+/// `processCSVChunk`/`getStreamResults`. Any other operation (including
+/// `rolling:`/`rolling_by:`) keeps every batch and stacks them into one
+/// frame, so the whole file is held in memory.
+fn stream_process_csv(file_path: &str, chunk_size: usize, operation: &str) -> PolarsResult<String> {
+    let file = File::open(file_path)
+        .map_err(|e| PolarsError::ComputeError(format!("Failed to open csv file: {}", e).into()))?;
+    let mut batched = CsvReader::new(file)
+        .with_chunk_size(chunk_size)
+        .with_try_parse_dates(true)
+        .batched(None)?;
+
+    let group_cols = parse_groupby_operation(operation).map(|(g, s)| (g.to_string(), s.to_string()));
+    let filter_col = parse_filter_operation(operation).map(|(c, v)| (c.to_string(), v));
+
+    let mut running_group_sums: HashMap<String, f64> = HashMap::new();
+    let mut filtered_chunks: Vec<DataFrame> = Vec::new();
+
+    while let Some(batches) = batched.next_batches(1)? {
+        for batch in batches {
+            if let Some((group_col, sum_col)) = &group_cols {
+                fold_batch_group_sums(&batch, group_col, sum_col, &mut running_group_sums)?;
+            } else if let Some((column, min_value)) = &filter_col {
+                let filtered = batch
+                    .lazy()
+                    .with_streaming(true)
+                    .filter(col(column).gt_eq(lit(*min_value)))
+                    .collect()?;
+                if filtered.height() > 0 {
+                    filtered_chunks.push(filtered);
+                }
             } else {
-                lf
+                filtered_chunks.push(batch);
             }
         }
-        op if op.starts_with("groupby:") => {
-            let parts: Vec<&str> = op.split(':').collect();
-            if parts.len() >= 3 {
-                let group_col = parts[1];
-                let sum_col = parts[2];
-                lf.group_by([col(group_col)])
-                  .agg([col(sum_col).sum()])
-            } else {
-                lf
-            }
-        }
-        _ => lf
-    };
-    
-    // Collect with streaming (if result is small enough)
-    let result_df = processed_lf.collect()?;
+    }
+
+    if let Some((group_col, sum_col)) = &group_cols {
+        let (groups, sums): (Vec<&str>, Vec<f64>) = running_group_sums
+            .iter()
+            .map(|(k, v)| (k.as_str(), *v))
+            .unzip();
+        let result_df = DataFrame::new(vec![Series::new(group_col, groups), Series::new(sum_col, sums)])?;
+        return dataframe_to_json(&result_df);
+    }
+
+    if filtered_chunks.is_empty() {
+        return dataframe_to_json(&DataFrame::empty());
+    }
+    let mut result_df = filtered_chunks[0].clone();
+    for chunk in &filtered_chunks[1..] {
+        result_df.vstack_mut(chunk)?;
+    }
+    if operation.starts_with("rolling:") || operation.starts_with("rolling_by:") {
+        result_df = apply_rolling_operation(result_df, operation)?;
+    }
     dataframe_to_json(&result_df)
 }
 
-// Utility functions for streaming
+// === NDJSON Ingestion ===
 
-fn dataframe_to_json_vec(df: &DataFrame) -> PolarsResult<Vec<HashMap<String, serde_json::Value>>> {
-    let mut result = Vec::new();
-    
-    for row in 0..df.height() {
-        let mut row_map = HashMap::new();
-        
-        for (col_idx, series) in df.get_columns().iter().enumerate() {
-            let col_name = df.get_column_names()[col_idx];
-            let value = series_value_to_json(series, row)?;
-            row_map.insert(col_name.to_string(), value);
-        }
-        result.push(row_map);
+/// Number of rows sampled per column when inferring an NDJSON schema.
+const NDJSON_SCHEMA_SAMPLE_SIZE: usize = 100;
+
+/// Read a newline-delimited JSON file into a DataFrame.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_readNdjson(
+    mut env: JNIEnv,
+    _class: JClass,
+    path: JString,
+) -> jstring {
+    let path_str: String = env.get_string(&path).unwrap().into();
+    let result = read_ndjson_file(&path_str);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
     }
-    
-    Ok(result)
 }
 
-fn json_vec_to_dataframe(data: &[HashMap<String, serde_json::Value>], schema: &Option<Vec<String>>) -> PolarsResult<DataFrame> {
-    if data.is_empty() {
+/// Read an ndjson file into a DataFrame.
+fn read_ndjson_file(path: &str) -> PolarsResult<String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| PolarsError::ComputeError(format!("Failed to read ndjson file: {}", e).into()))?;
+    let rows = parse_ndjson_rows(&content)?;
+    let df = ndjson_rows_to_dataframe(&rows)?;
+    dataframe_to_json(&df)
+}
+
+/// Parse each non-blank line of an ndjson payload into a JSON object, erroring
+/// on any line that isn't a JSON object (ndjson rows must be records).
+fn parse_ndjson_rows(content: &str) -> PolarsResult<Vec<serde_json::Map<String, serde_json::Value>>> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            match serde_json::from_str::<serde_json::Value>(line) {
+                Ok(serde_json::Value::Object(map)) => Ok(map),
+                Ok(_) => Err(PolarsError::ComputeError("ndjson row is not a JSON object".into())),
+                Err(e) => Err(PolarsError::ComputeError(format!("Invalid JSON in ndjson row: {}", e).into())),
+            }
+        })
+        .collect()
+}
+
+/// Infer a schema over a sample of rows and build a typed column for each
+/// field, falling back to Utf8 when a column's sampled values disagree.
+fn ndjson_rows_to_dataframe(rows: &[serde_json::Map<String, serde_json::Value>]) -> PolarsResult<DataFrame> {
+    if rows.is_empty() {
         return Ok(DataFrame::empty());
     }
-    
-    let default_columns: Vec<String> = if let Some(first_row) = data.first() {
-        first_row.keys().cloned().collect()
-    } else {
-        Vec::new()
-    };
-    let columns = schema.as_ref().unwrap_or(&default_columns);
-    let mut series_vec = Vec::new();
-    
-    for col_name in columns {
-        let values: Vec<AnyValue> = data.iter()
-            .map(|row| json_value_to_any_value(row.get(col_name).unwrap_or(&serde_json::Value::Null)))
+
+    let mut column_order: Vec<String> = Vec::new();
+    for row in rows {
+        for key in row.keys() {
+            if !column_order.contains(key) {
+                column_order.push(key.clone());
+            }
+        }
+    }
+
+    let sample_size = rows.len().min(NDJSON_SCHEMA_SAMPLE_SIZE);
+    let mut series_vec = Vec::with_capacity(column_order.len());
+    for col_name in &column_order {
+        let sample: Vec<&serde_json::Value> = rows[..sample_size]
+            .iter()
+            .filter_map(|row| row.get(col_name))
+            .collect();
+        let dtype = infer_ndjson_dtype(&sample);
+        let values: Vec<&serde_json::Value> = rows
+            .iter()
+            .map(|row| row.get(col_name).unwrap_or(&serde_json::Value::Null))
             .collect();
-        
-        let series = Series::new(col_name, &values);
-        series_vec.push(series);
+        series_vec.push(build_ndjson_series(col_name, &values, &dtype)?);
     }
-    
+
     DataFrame::new(series_vec)
 }
 
-fn series_value_to_json(series: &Series, row: usize) -> PolarsResult<serde_json::Value> {
-    match series.dtype() {
-        DataType::Utf8 => {
-            if let Ok(val) = series.utf8() {
-                Ok(json!(val.get(row).unwrap_or("")))
-            } else {
-                Ok(json!(""))
+/// The set of column types the ndjson builder can produce.
+enum NdjsonDtype {
+    Boolean,
+    Int32,
+    Int64,
+    UInt64,
+    Float64,
+    Utf8,
+    Struct(Vec<(String, NdjsonDtype)>),
+}
+
+/// Infer a column's dtype from a sample of its non-null values, falling back
+/// to Utf8 when the sample isn't uniformly one JSON type.
+fn infer_ndjson_dtype(sample: &[&serde_json::Value]) -> NdjsonDtype {
+    let non_null: Vec<&&serde_json::Value> = sample.iter().filter(|v| !v.is_null()).collect();
+    let Some(first) = non_null.first() else {
+        return NdjsonDtype::Utf8;
+    };
+
+    match first {
+        serde_json::Value::Object(first_obj) => {
+            if !non_null.iter().all(|v| v.is_object()) {
+                return NdjsonDtype::Utf8;
+            }
+            let mut fields = Vec::with_capacity(first_obj.len());
+            for key in first_obj.keys() {
+                let field_sample: Vec<&serde_json::Value> = non_null
+                    .iter()
+                    .filter_map(|v| v.as_object().and_then(|o| o.get(key)))
+                    .collect();
+                fields.push((key.clone(), infer_ndjson_dtype(&field_sample)));
             }
+            NdjsonDtype::Struct(fields)
         }
-        DataType::Int64 => {
-            if let Ok(val) = series.i64() {
-                Ok(json!(val.get(row).unwrap_or(0)))
+        serde_json::Value::Bool(_) => {
+            if non_null.iter().all(|v| v.is_boolean()) {
+                NdjsonDtype::Boolean
             } else {
-                Ok(json!(0))
+                NdjsonDtype::Utf8
             }
         }
-        DataType::Float64 => {
-            if let Ok(val) = series.f64() {
-                Ok(json!(val.get(row).unwrap_or(0.0)))
+        serde_json::Value::Number(_) => {
+            if !non_null.iter().all(|v| v.is_number()) {
+                return NdjsonDtype::Utf8;
+            }
+            if non_null.iter().any(|v| v.as_f64().map_or(false, |f| f.fract() != 0.0)) {
+                NdjsonDtype::Float64
+            } else if non_null.iter().any(|v| v.as_i64().is_none() && v.as_u64().is_some()) {
+                NdjsonDtype::UInt64
+            } else if non_null.iter().all(|v| v.as_i64().map_or(false, |i| i >= i32::MIN as i64 && i <= i32::MAX as i64)) {
+                NdjsonDtype::Int32
             } else {
-                Ok(json!(0.0))
+                NdjsonDtype::Int64
             }
         }
-        DataType::Int32 => {
-            if let Ok(val) = series.i32() {
-                Ok(json!(val.get(row).unwrap_or(0)))
-            } else {
-                Ok(json!(0))
+        serde_json::Value::String(_) => NdjsonDtype::Utf8,
+        serde_json::Value::Array(_) | serde_json::Value::Null => NdjsonDtype::Utf8,
+    }
+}
+
+/// Build a typed Series for a column given its inferred dtype.
+fn build_ndjson_series(name: &str, values: &[&serde_json::Value], dtype: &NdjsonDtype) -> PolarsResult<Series> {
+    match dtype {
+        NdjsonDtype::Boolean => Ok(Series::new(name, values.iter().map(|v| v.as_bool()).collect::<Vec<_>>())),
+        // `Int32` is inferred from a sample (see `NDJSON_SCHEMA_SAMPLE_SIZE`)
+        // but applied to the whole column, so a later value can be out of
+        // i32's range; use a checked conversion rather than a truncating
+        // `as i32` cast, turning a corrupting wraparound into a null.
+        NdjsonDtype::Int32 => Ok(Series::new(
+            name,
+            values.iter().map(|v| v.as_i64().and_then(|i| i32::try_from(i).ok())).collect::<Vec<_>>(),
+        )),
+        NdjsonDtype::Int64 => Ok(Series::new(name, values.iter().map(|v| v.as_i64()).collect::<Vec<_>>())),
+        NdjsonDtype::UInt64 => Ok(Series::new(name, values.iter().map(|v| v.as_u64()).collect::<Vec<_>>())),
+        NdjsonDtype::Float64 => Ok(Series::new(name, values.iter().map(|v| v.as_f64()).collect::<Vec<_>>())),
+        NdjsonDtype::Utf8 => Ok(Series::new(
+            name,
+            values
+                .iter()
+                .map(|v| match v {
+                    serde_json::Value::Null => None,
+                    serde_json::Value::String(s) => Some(s.clone()),
+                    other => Some(other.to_string()),
+                })
+                .collect::<Vec<_>>(),
+        )),
+        NdjsonDtype::Struct(fields) => {
+            let mut field_series = Vec::with_capacity(fields.len());
+            for (field_name, field_dtype) in fields {
+                let field_values: Vec<&serde_json::Value> = values
+                    .iter()
+                    .map(|v| {
+                        v.as_object()
+                            .and_then(|o| o.get(field_name))
+                            .unwrap_or(&serde_json::Value::Null)
+                    })
+                    .collect();
+                field_series.push(build_ndjson_series(field_name, &field_values, field_dtype)?);
             }
+            Ok(StructChunked::new(name, &field_series)?.into_series())
         }
-        DataType::Float32 => {
-            if let Ok(val) = series.f32() {
-                Ok(json!(val.get(row).unwrap_or(0.0)))
+    }
+}
+
+/// Initialize a streaming ndjson processor session. Shares the same
+/// processor table as `initStreamProcessor` (and an ndjson session's rows
+/// live in `StreamProcessor::ndjson_rows`); only the buffering differs, not
+/// the session bookkeeping.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_initNdjsonStreamProcessor(
+    mut env: JNIEnv,
+    _class: JClass,
+    operation: JString,
+) -> jlong {
+    let operation_str: String = env.get_string(&operation).unwrap().into();
+    register_stream_processor(operation_str)
+}
+
+/// Process a chunk of ndjson data as bytes (one or more complete lines).
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_processNdjsonChunk(
+    mut env: JNIEnv,
+    _class: JClass,
+    processor_id: jlong,
+    chunk_data: JByteArray,
+) -> jstring {
+    let result = process_ndjson_chunk_internal(&mut env, processor_id, chunk_data);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn process_ndjson_chunk_internal(env: &mut JNIEnv, processor_id: jlong, chunk_data: JByteArray) -> PolarsResult<String> {
+    let chunk_bytes = env.convert_byte_array(chunk_data).map_err(|e|
+        PolarsError::ComputeError(format!("Failed to read chunk data: {}", e).into()))?;
+    let chunk_str = String::from_utf8(chunk_bytes).map_err(|e|
+        PolarsError::ComputeError(format!("Invalid UTF-8 in chunk: {}", e).into()))?;
+
+    let rows = parse_ndjson_rows(&chunk_str)?;
+
+    let mut processors = STREAM_PROCESSORS.lock().unwrap();
+    let processor = processors.get_mut(&processor_id).ok_or_else(||
+        PolarsError::ComputeError("Invalid processor ID".into()))?;
+
+    processor.ndjson_rows.extend(rows);
+
+    Ok(format!("{{\"total_accumulated\": {}}}", processor.ndjson_rows.len()))
+}
+
+/// Get final results from an ndjson stream processor, running the inferred
+/// schema pass over all accumulated rows before applying `operation`.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_getNdjsonStreamResults(
+    mut env: JNIEnv,
+    _class: JClass,
+    processor_id: jlong,
+    operation: JString,
+) -> jstring {
+    let operation_str: String = env.get_string(&operation).unwrap().into();
+    let result = get_ndjson_stream_results_internal(processor_id, &operation_str);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn get_ndjson_stream_results_internal(processor_id: jlong, operation: &str) -> PolarsResult<String> {
+    let processors = STREAM_PROCESSORS.lock().unwrap();
+    let processor = processors.get(&processor_id).ok_or_else(||
+        PolarsError::ComputeError("Invalid processor ID".into()))?;
+
+    if processor.ndjson_rows.is_empty() {
+        return Ok("[]".to_string());
+    }
+
+    let df = ndjson_rows_to_dataframe(&processor.ndjson_rows)?;
+    let op = if operation.is_empty() { processor.operation.as_str() } else { operation };
+
+    let result_df = match op {
+        op if op.starts_with("filter:") => {
+            let parts: Vec<&str> = op.split(':').collect();
+            if parts.len() >= 3 {
+                let column = parts[1];
+                let min_value: f64 = parts[2].parse().unwrap_or(0.0);
+                df.lazy().filter(col(column).gt_eq(lit(min_value))).collect()?
             } else {
-                Ok(json!(0.0))
+                df
             }
         }
-        _ => {
-            if let Ok(any_val) = series.get(row) {
-                Ok(json!(any_val.to_string()))
+        op if op.starts_with("groupby:") => {
+            let parts: Vec<&str> = op.split(':').collect();
+            if parts.len() >= 3 {
+                let group_col = parts[1];
+                let sum_col = parts[2];
+                df.lazy().group_by([col(group_col)]).agg([col(sum_col).sum()]).collect()?
             } else {
-                Ok(json!("null"))
+                df
             }
         }
+        _ => df,
+    };
+
+    dataframe_to_json(&result_df)
+}
+
+/// Close and cleanup an ndjson stream processor (same processor table as
+/// `closeStreamProcessor`).
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_closeNdjsonStreamProcessor(
+    _env: JNIEnv,
+    _class: JClass,
+    processor_id: jlong,
+) {
+    let mut processors = STREAM_PROCESSORS.lock().unwrap();
+    processors.remove(&processor_id);
+}
+
+// === DataFrame Joins ===
+
+fn parse_join_type(how: &str) -> JoinType {
+    match how.to_lowercase().as_str() {
+        "left" => JoinType::Left,
+        "outer" => JoinType::Outer,
+        "cross" => JoinType::Cross,
+        "semi" => JoinType::Semi,
+        "anti" => JoinType::Anti,
+        _ => JoinType::Inner,
+    }
+}
+
+/// Join two CSV-backed DataFrames on one or more key columns.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_joinDataFrames(
+    mut env: JNIEnv,
+    _class: JClass,
+    left_path: JString,
+    right_path: JString,
+    left_keys: JObjectArray,
+    right_keys: JObjectArray,
+    how: JString,
+) -> jstring {
+    let left_path_str: String = env.get_string(&left_path).unwrap().into();
+    let right_path_str: String = env.get_string(&right_path).unwrap().into();
+    let left_keys_vec = jstring_array_to_vec(&mut env, &left_keys);
+    let right_keys_vec = jstring_array_to_vec(&mut env, &right_keys);
+    let how_str: String = env.get_string(&how).unwrap().into();
+
+    let result = join_dataframes(&left_path_str, &right_path_str, &left_keys_vec, &right_keys_vec, &how_str);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn join_dataframes(
+    left_path: &str,
+    right_path: &str,
+    left_keys: &[String],
+    right_keys: &[String],
+    how: &str,
+) -> PolarsResult<String> {
+    let left_df = CsvReader::from_path(left_path)?.finish()?;
+    let right_df = CsvReader::from_path(right_path)?.finish()?;
+
+    let left_on: Vec<Expr> = left_keys.iter().map(|k| col(k)).collect();
+    let right_on: Vec<Expr> = right_keys.iter().map(|k| col(k)).collect();
+
+    let joined = left_df
+        .lazy()
+        .join(right_df.lazy(), left_on, right_on, JoinArgs::new(parse_join_type(how)))
+        .collect()?;
+
+    dataframe_to_json(&joined)
+}
+
+/// As-of join two CSV-backed DataFrames: match each left row to the nearest
+/// right row by key rather than an equal key. Both frames are sorted by the
+/// join key; a "backward" strategy (the default) picks the last right row
+/// whose key is <= the left key, "forward" picks the first right row whose
+/// key is >= the left key. Optionally scoped by equality on `by` and bounded
+/// by `tolerance` (both empty strings mean "not set").
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_asofJoin(
+    mut env: JNIEnv,
+    _class: JClass,
+    left_path: JString,
+    right_path: JString,
+    left_on: JString,
+    right_on: JString,
+    by: JString,
+    strategy: JString,
+    tolerance: JString,
+) -> jstring {
+    let left_path_str: String = env.get_string(&left_path).unwrap().into();
+    let right_path_str: String = env.get_string(&right_path).unwrap().into();
+    let left_on_str: String = env.get_string(&left_on).unwrap().into();
+    let right_on_str: String = env.get_string(&right_on).unwrap().into();
+    let by_str: String = env.get_string(&by).unwrap().into();
+    let strategy_str: String = env.get_string(&strategy).unwrap().into();
+    let tolerance_str: String = env.get_string(&tolerance).unwrap().into();
+
+    let result = asof_join(
+        &left_path_str,
+        &right_path_str,
+        &left_on_str,
+        &right_on_str,
+        (!by_str.is_empty()).then_some(by_str.as_str()),
+        &strategy_str,
+        (!tolerance_str.is_empty()).then_some(tolerance_str.as_str()),
+    );
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn asof_join(
+    left_path: &str,
+    right_path: &str,
+    left_on: &str,
+    right_on: &str,
+    by: Option<&str>,
+    strategy: &str,
+    tolerance: Option<&str>,
+) -> PolarsResult<String> {
+    let left_df = CsvReader::from_path(left_path)?.finish()?;
+    let right_df = CsvReader::from_path(right_path)?.finish()?;
+
+    let asof_strategy = match strategy.to_lowercase().as_str() {
+        "forward" => AsofStrategy::Forward,
+        _ => AsofStrategy::Backward,
+    };
+    let asof_options = AsOfOptions {
+        strategy: asof_strategy,
+        left_by: by.map(|b| vec![b.to_string()]),
+        right_by: by.map(|b| vec![b.to_string()]),
+        tolerance: tolerance.and_then(|t| t.parse::<f64>().ok()).map(AnyValue::Float64),
+        tolerance_str: None,
+    };
+
+    // Polars' as-of join assumes both inputs are pre-sorted on the join key;
+    // sort here rather than trust the caller's CSV ordering.
+    let left_sorted = left_df.lazy().sort(left_on, SortOptions::default());
+    let right_sorted = right_df.lazy().sort(right_on, SortOptions::default());
+
+    let joined = left_sorted
+        .join_builder()
+        .with(right_sorted)
+        .left_on([col(left_on)])
+        .right_on([col(right_on)])
+        .how(JoinType::AsOf(asof_options))
+        .finish()
+        .collect()?;
+
+    dataframe_to_json(&joined)
+}
+
+// === Rolling-window Aggregations ===
+
+/// Build the rolling expression for `fn_name` over `column` with a fixed
+/// `window_size`, defaulting to mean for an unrecognized function name.
+fn rolling_expr(column: &str, window_size: usize, fn_name: &str) -> Expr {
+    let opts = RollingOptionsFixedWindow {
+        window_size,
+        min_periods: window_size,
+        weights: None,
+        center: false,
+        fn_params: None,
+    };
+    match fn_name {
+        "sum" => col(column).rolling_sum(opts),
+        "min" => col(column).rolling_min(opts),
+        "max" => col(column).rolling_max(opts),
+        "std" => col(column).rolling_std(opts),
+        _ => col(column).rolling_mean(opts),
+    }
+}
+
+/// Build the dynamic-window aggregation expression for `fn_name`,
+/// defaulting to mean for an unrecognized function name.
+fn dynamic_agg_expr(column: &str, fn_name: &str) -> Expr {
+    match fn_name {
+        "sum" => col(column).sum(),
+        "min" => col(column).min(),
+        "max" => col(column).max(),
+        "std" => col(column).std(1),
+        _ => col(column).mean(),
+    }
+}
+
+/// Apply a `rolling:<col>:<window>:<fn>` or
+/// `rolling_by:<col>:<time_col>:<duration>[:<fn>]` operation string to `df`.
+fn apply_rolling_operation(df: DataFrame, op: &str) -> PolarsResult<DataFrame> {
+    if let Some(rest) = op.strip_prefix("rolling_by:") {
+        let parts: Vec<&str> = rest.split(':').collect();
+        if parts.len() < 3 {
+            return Ok(df);
+        }
+        let (column, time_col, duration) = (parts[0], parts[1], parts[2]);
+        let fn_name = parts.get(3).copied().unwrap_or("mean");
+        return df
+            .lazy()
+            .sort(time_col, SortOptions::default())
+            .group_by_dynamic(
+                col(time_col),
+                [],
+                DynamicGroupOptions {
+                    every: Duration::parse(duration),
+                    period: Duration::parse(duration),
+                    offset: Duration::parse("0ns"),
+                    ..Default::default()
+                },
+            )
+            .agg([dynamic_agg_expr(column, fn_name).alias(&format!("{}_rolling_{}", column, fn_name))])
+            .collect();
+    }
+
+    if let Some(rest) = op.strip_prefix("rolling:") {
+        let parts: Vec<&str> = rest.split(':').collect();
+        if parts.len() < 3 {
+            return Ok(df);
+        }
+        let (column, window, fn_name) = (parts[0], parts[1], parts[2]);
+        let window_size: usize = window.parse().unwrap_or(1);
+        return df
+            .lazy()
+            .with_column(rolling_expr(column, window_size, fn_name).alias(&format!("{}_rolling_{}", column, fn_name)))
+            .collect();
+    }
+
+    Ok(df)
+}
+
+/// Compute a fixed-window rolling aggregation over a CSV file directly,
+/// without a streaming session.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_rollingAggregate(
+    mut env: JNIEnv,
+    _class: JClass,
+    csv_path: JString,
+    column: JString,
+    window: jint,
+    agg_fn: JString,
+) -> jstring {
+    let csv_path_str: String = env.get_string(&csv_path).unwrap().into();
+    let column_str: String = env.get_string(&column).unwrap().into();
+    let agg_fn_str: String = env.get_string(&agg_fn).unwrap().into();
+
+    let result = rolling_aggregate(&csv_path_str, &column_str, window as usize, &agg_fn_str);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn rolling_aggregate(csv_path: &str, column: &str, window: usize, agg_fn: &str) -> PolarsResult<String> {
+    let df = CsvReader::from_path(csv_path)?.finish()?;
+    let result_df = df
+        .lazy()
+        .with_column(rolling_expr(column, window, agg_fn).alias(&format!("{}_rolling_{}", column, agg_fn)))
+        .collect()?;
+    dataframe_to_json(&result_df)
+}
+
+// === Generic Lazy Query Pipeline ===
+
+#[derive(Deserialize)]
+struct QuerySpec {
+    select: Option<Vec<String>>,
+    filters: Option<Vec<QueryFilter>>,
+    #[serde(default = "default_filter_logic")]
+    filter_logic: String,
+    with_columns: Option<Vec<QueryWithColumn>>,
+    group_by: Option<Vec<String>>,
+    aggregations: Option<Vec<QueryAggregation>>,
+    sort: Option<Vec<QuerySort>>,
+    limit: Option<u32>,
+}
+
+fn default_filter_logic() -> String {
+    "and".to_string()
+}
+
+#[derive(Deserialize)]
+struct QueryFilter {
+    column: String,
+    op: String,
+    value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct QueryWithColumn {
+    name: String,
+    left: String,
+    op: String,
+    right: serde_json::Value,
+    #[serde(default)]
+    right_is_column: bool,
+}
+
+#[derive(Deserialize)]
+struct QueryAggregation {
+    column: String,
+    func: String,
+    alias: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct QuerySort {
+    column: String,
+    #[serde(default)]
+    descending: bool,
+}
+
+/// Run a JSON-encoded query pipeline against a CSV file.
+#[no_mangle]
+pub extern "system" fn Java_com_mlscala_polars_PolarsJNI_runQuery(
+    mut env: JNIEnv,
+    _class: JClass,
+    path: JString,
+    query_json: JString,
+) -> jstring {
+    let path_str: String = env.get_string(&path).unwrap().into();
+    let query_json_str: String = env.get_string(&query_json).unwrap().into();
+    let result = run_query(&path_str, &query_json_str);
+    match result {
+        Ok(json_str) => env.new_string(json_str).unwrap().into_raw(),
+        Err(e) => env.new_string(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+fn run_query(path: &str, query_json: &str) -> PolarsResult<String> {
+    let spec: QuerySpec = serde_json::from_str(query_json)
+        .map_err(|e| PolarsError::ComputeError(format!("Invalid query JSON: {}", e).into()))?;
+
+    let df = CsvReader::from_path(path)?.finish()?;
+    let mut lf = df.lazy();
+
+    if let Some(filters) = &spec.filters {
+        if let Some(combined) = combine_filter_exprs(filters, &spec.filter_logic) {
+            lf = lf.filter(combined);
+        }
+    }
+
+    if let Some(with_columns) = &spec.with_columns {
+        for wc in with_columns {
+            lf = lf.with_column(query_with_column_expr(wc).alias(&wc.name));
+        }
+    }
+
+    if let Some(group_cols) = &spec.group_by {
+        let keys: Vec<Expr> = group_cols.iter().map(|c| col(c)).collect();
+        let aggs: Vec<Expr> = spec
+            .aggregations
+            .as_ref()
+            .map(|aggs| aggs.iter().map(query_aggregation_expr).collect())
+            .unwrap_or_default();
+        lf = lf.group_by(keys).agg(aggs);
+    }
+
+    if let Some(select_cols) = &spec.select {
+        let exprs: Vec<Expr> = select_cols.iter().map(|c| col(c)).collect();
+        lf = lf.select(exprs);
+    }
+
+    if let Some(sorts) = &spec.sort {
+        if !sorts.is_empty() {
+            let by_columns: Vec<&str> = sorts.iter().map(|s| s.column.as_str()).collect();
+            let descending: Vec<bool> = sorts.iter().map(|s| s.descending).collect();
+            lf = lf.sort_by_exprs(
+                by_columns.iter().map(|c| col(c)).collect::<Vec<_>>(),
+                descending,
+                false,
+                false,
+            );
+        }
+    }
+
+    if let Some(limit) = spec.limit {
+        lf = lf.limit(limit);
+    }
+
+    let result_df = lf.collect()?;
+    dataframe_to_json(&result_df)
+}
+
+/// Fold a list of filters into a single expression, combined via "and" or
+/// "or" (anything other than "or" is treated as "and").
+fn combine_filter_exprs(filters: &[QueryFilter], logic: &str) -> Option<Expr> {
+    let mut exprs = filters.iter().map(query_filter_expr);
+    let first = exprs.next()?;
+    Some(exprs.fold(first, |acc, next| {
+        if logic.eq_ignore_ascii_case("or") {
+            acc.or(next)
+        } else {
+            acc.and(next)
+        }
+    }))
+}
+
+fn query_filter_expr(filter: &QueryFilter) -> Expr {
+    let value = json_value_to_lit(&filter.value);
+    match filter.op.as_str() {
+        "<" => col(&filter.column).lt(value),
+        "<=" => col(&filter.column).lt_eq(value),
+        "==" => col(&filter.column).eq(value),
+        "!=" => col(&filter.column).neq(value),
+        ">" => col(&filter.column).gt(value),
+        ">=" => col(&filter.column).gt_eq(value),
+        _ => col(&filter.column).eq(value),
+    }
+}
+
+fn query_with_column_expr(wc: &QueryWithColumn) -> Expr {
+    let left = col(&wc.left);
+    let right = if wc.right_is_column {
+        wc.right.as_str().map(col).unwrap_or_else(|| lit(NULL))
+    } else {
+        json_value_to_lit(&wc.right)
+    };
+    match wc.op.as_str() {
+        "+" => left + right,
+        "-" => left - right,
+        "*" => left * right,
+        "/" => left / right,
+        _ => left,
+    }
+}
+
+fn query_aggregation_expr(agg: &QueryAggregation) -> Expr {
+    let base = col(&agg.column);
+    let expr = match agg.func.as_str() {
+        "mean" => base.mean(),
+        "min" => base.min(),
+        "max" => base.max(),
+        "count" => base.count(),
+        "n_unique" => base.n_unique(),
+        _ => base.sum(),
+    };
+    match &agg.alias {
+        Some(alias) => expr.alias(alias),
+        None => expr,
     }
 }
 
-fn json_value_to_any_value(value: &serde_json::Value) -> AnyValue {
+fn json_value_to_lit(value: &serde_json::Value) -> Expr {
     match value {
-        serde_json::Value::Null => AnyValue::Null,
-        serde_json::Value::Bool(b) => AnyValue::Boolean(*b),
         serde_json::Value::Number(n) => {
             if let Some(i) = n.as_i64() {
-                AnyValue::Int64(i)
-            } else if let Some(f) = n.as_f64() {
-                AnyValue::Float64(f)
+                lit(i)
             } else {
-                AnyValue::Null
+                lit(n.as_f64().unwrap_or(0.0))
             }
         }
-        serde_json::Value::String(s) => AnyValue::Utf8(s.as_str()),
-        _ => AnyValue::Utf8("null"),
+        serde_json::Value::String(s) => lit(s.clone()),
+        serde_json::Value::Bool(b) => lit(*b),
+        _ => lit(NULL),
     }
 }